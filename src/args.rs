@@ -0,0 +1,20 @@
+use clap::{Parser, Subcommand};
+
+use crate::commands::{decode::DecodeArgs, encode::EncodeArgs, listen::ListenArgs};
+
+#[derive(Debug, Parser)]
+#[command(author, version, about)]
+pub struct Args {
+    #[command(subcommand)]
+    pub subcommand: Command,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Decode an audio recording (WAV, MP3, FLAC, OGG, ...) of a TRS-80 cassette.
+    Decode(DecodeArgs),
+    /// Decode a TRS-80 cassette live from the default input device.
+    Listen(ListenArgs),
+    /// Encode a file into a TRS-80 cassette WAV recording.
+    Encode(EncodeArgs),
+}
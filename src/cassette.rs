@@ -1,4 +1,4 @@
-use std::ops::Range;
+use std::{ops::Range, sync::mpsc::Sender};
 
 use anyhow::{bail, ensure, Result};
 use bitvec::{order::Msb0, vec::BitVec, view::BitView};
@@ -14,9 +14,20 @@ pub const PULSE_START: Range<f32> = (41.0 / 44100.0)..(46.0 / 44100.0);
 pub const PULSE_END: f32 = 20000.0 / 44100.0;
 
 /// The start sequence is 01111111.
-const START_SEQUENCE: u8 = 0x7F;
+pub(crate) const START_SEQUENCE: u8 = 0x7F;
 const INT_CROSS_THRESHOLD: i32 = (CROSS_THRESHOLD * i16::MAX as f32) as i32;
 
+/// Half-width (in samples) of the windowed-sinc kernel used by
+/// [`CrossingMode::Sinc`].
+const LANCZOS_A: f64 = 3.0;
+
+/// [`CrossingMode::Sinc`] narrows its search window until it is smaller
+/// than this many samples.
+const SINC_SEARCH_EPSILON: f64 = 1e-3;
+
+/// Cutoff of the one-pole envelope follower used by [`AgcOptions`].
+const AGC_ENV_CUTOFF_HZ: f32 = 200.0;
+
 #[derive(Debug)]
 enum Pulse {
     Start,
@@ -29,17 +40,112 @@ pub struct Spec {
     channels: u16,
 }
 
+/// Interpolation strategy used to refine a zero-crossing to sub-sample
+/// precision, trading accuracy for decode speed.
+#[derive(Debug, Clone, Copy, Default)]
+pub enum CrossingMode {
+    /// Linear interpolation between the two bracketing samples.
+    #[default]
+    Linear,
+    /// Binary search over a windowed-sinc (Lanczos) reconstruction of the
+    /// signal between the bracketing samples.
+    Sinc,
+}
+
+/// Options controlling how [`decode`] locates zero-crossings.
+#[derive(Debug, Clone, Copy)]
+pub struct DecodeOptions {
+    /// Interpolation strategy used to refine crossing positions.
+    pub crossing_mode: CrossingMode,
+    /// The signal level a crossing is measured against, in raw PCM units.
+    pub crossing_limit: f64,
+    /// Automatic gain control for the crossing threshold.
+    pub agc: AgcOptions,
+}
+
+impl Default for DecodeOptions {
+    fn default() -> Self {
+        Self {
+            crossing_mode: CrossingMode::default(),
+            crossing_limit: 0.0,
+            agc: AgcOptions::default(),
+        }
+    }
+}
+
+/// Adaptive threshold settings, letting [`decode`] track a tape's
+/// amplitude instead of gating on the fixed [`CROSS_THRESHOLD`].
+#[derive(Debug, Clone, Copy)]
+pub struct AgcOptions {
+    /// Track a running peak-magnitude envelope and threshold crossings
+    /// against a fraction of it, rather than against a fixed level.
+    pub enabled: bool,
+    /// Fraction of the local envelope a sample must exceed to be
+    /// considered a crossing candidate.
+    pub threshold_fraction: f32,
+    /// Floor for the envelope, in raw PCM units, so silent gaps don't
+    /// amplify noise into false crossings.
+    pub min_level: f32,
+}
+
+impl Default for AgcOptions {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            threshold_fraction: CROSS_THRESHOLD,
+            min_level: i16::MAX as f32 * CROSS_THRESHOLD,
+        }
+    }
+}
+
+/// The per-sample decay factor of a one-pole envelope follower with cutoff
+/// `cutoff_hz` at the given `sample_rate`.
+fn agc_decay(sample_rate: u32, cutoff_hz: f32) -> f32 {
+    (-2.0 * std::f32::consts::PI * cutoff_hz / sample_rate as f32).exp()
+}
+
+/// One-shot decode with default options. Callers that need AGC or a
+/// non-default crossing mode build a `DecodeOptions` and call
+/// [`decode_with_options`] directly, so this currently has no caller
+/// outside the round-trip test in [`crate::encode`].
+#[allow(dead_code)]
 pub fn decode(samples: &[i32], spec: Spec) -> Result<Vec<BitVec<u8, Msb0>>> {
-    let mut intersections = Vec::new();
+    decode_with_options(samples, spec, DecodeOptions::default())
+}
+
+pub fn decode_with_options(
+    samples: &[i32],
+    spec: Spec,
+    opts: DecodeOptions,
+) -> Result<Vec<BitVec<u8, Msb0>>> {
+    let agc_decay_factor = agc_decay(spec.sample_rate, AGC_ENV_CUTOFF_HZ);
+    let mut env = opts.agc.min_level;
+
+    let mut intersections: Vec<f64> = Vec::new();
     let mut last = (0_i32, 0_usize);
-    for (i, sample) in samples.into_iter().enumerate() {
+    for (i, sample) in samples.iter().enumerate() {
         if i % spec.channels as usize != 0 {
             continue;
         }
 
-        if sample.abs() > INT_CROSS_THRESHOLD {
+        let threshold = if opts.agc.enabled {
+            env = (sample.unsigned_abs() as f32)
+                .max(env * agc_decay_factor)
+                .max(opts.agc.min_level);
+            (env * opts.agc.threshold_fraction) as i32
+        } else {
+            INT_CROSS_THRESHOLD
+        };
+
+        if sample.abs() > threshold {
             if last.0.signum() != sample.signum() && last.0.signum() == -1 {
-                intersections.push(i);
+                intersections.push(refine_crossing(
+                    samples,
+                    spec.channels as usize,
+                    last,
+                    (*sample, i),
+                    opts,
+                ));
             }
             last = (*sample, i);
         }
@@ -75,13 +181,15 @@ pub fn decode(samples: &[i32], spec: Spec) -> Result<Vec<BitVec<u8, Msb0>>> {
             match pulse {
                 Pulse::Zero => dat.push(false),
                 Pulse::One => dat.push(true),
-                Pulse::Start if active => ensure!(dat.len() % 8 == 0, "Invalid start pulse"),
+                Pulse::Start if active => {
+                    ensure!(dat.len().is_multiple_of(8), "Invalid start pulse")
+                }
                 Pulse::Start => dat.push(false),
             }
 
             if !active
                 && dat.len() >= 8
-                && &dat[dat.len() - 8..] == START_SEQUENCE.view_bits::<Msb0>()
+                && dat[dat.len() - 8..] == START_SEQUENCE.view_bits::<Msb0>()
             {
                 active = true;
                 dat.clear();
@@ -96,6 +204,250 @@ pub fn decode(samples: &[i32], spec: Spec) -> Result<Vec<BitVec<u8, Msb0>>> {
     Ok(raw_sections)
 }
 
+/// Incremental, stateful counterpart to [`decode`] for live capture (e.g.
+/// from a `cpal` input callback), where samples arrive as a series of
+/// arbitrarily-sized buffers rather than one complete slice.
+///
+/// Crossings are always refined with linear interpolation: [`CrossingMode::Sinc`]
+/// needs to look behind the crossing into already-consumed buffers, which a
+/// streaming decoder can no longer access.
+pub struct Decoder {
+    spec: Spec,
+    opts: DecodeOptions,
+    channel: u16,
+    agc_decay_factor: f32,
+    env: f32,
+    sample_index: usize,
+    last: (i32, f64),
+    last_crossing: Option<f64>,
+    pulses: Vec<Pulse>,
+    dat: BitVec<u8, Msb0>,
+    active: bool,
+    sections: Sender<BitVec<u8, Msb0>>,
+}
+
+impl Decoder {
+    /// Creates a decoder that samples `channel` of the incoming stream and
+    /// sends each completed section to `sections` as soon as a
+    /// [`PULSE_END`] gap is seen.
+    pub fn new(
+        spec: Spec,
+        channel: u16,
+        opts: DecodeOptions,
+        sections: Sender<BitVec<u8, Msb0>>,
+    ) -> Self {
+        let agc_decay_factor = agc_decay(spec.sample_rate, AGC_ENV_CUTOFF_HZ);
+        Self {
+            agc_decay_factor,
+            env: opts.agc.min_level,
+            sample_index: 0,
+            last: (0, 0.0),
+            last_crossing: None,
+            pulses: Vec::new(),
+            dat: BitVec::new(),
+            active: false,
+            spec,
+            channel,
+            opts,
+            sections,
+        }
+    }
+
+    /// Feeds the next buffer of interleaved PCM samples into the decoder.
+    pub fn push(&mut self, samples: &[i32]) -> Result<()> {
+        for (offset, &sample) in samples.iter().enumerate() {
+            let i = self.sample_index + offset;
+            if i % self.spec.channels as usize != self.channel as usize {
+                continue;
+            }
+
+            let threshold = if self.opts.agc.enabled {
+                self.env = (sample.unsigned_abs() as f32)
+                    .max(self.env * self.agc_decay_factor)
+                    .max(self.opts.agc.min_level);
+                (self.env * self.opts.agc.threshold_fraction) as i32
+            } else {
+                INT_CROSS_THRESHOLD
+            };
+
+            if sample.abs() > threshold {
+                if self.last.0.signum() != sample.signum() && self.last.0.signum() == -1 {
+                    let (prev, cur) = (self.last.0 as f64, sample as f64);
+                    let pos = self.last.1
+                        + (self.opts.crossing_limit - prev) / (cur - prev)
+                            * (i as f64 - self.last.1);
+                    self.push_crossing(pos)?;
+                }
+                self.last = (sample, i as f64);
+            }
+        }
+
+        self.sample_index += samples.len();
+        Ok(())
+    }
+
+    /// Signals the end of the stream, flushing any in-progress section.
+    pub fn finish(mut self) -> Result<()> {
+        self.flush_section()
+    }
+
+    fn push_crossing(&mut self, pos: f64) -> Result<()> {
+        let Some(prev_pos) = self.last_crossing.replace(pos) else {
+            return Ok(());
+        };
+
+        let diff = (pos - prev_pos) as f32 / self.spec.sample_rate as f32;
+        if PULSE_ONE.contains(&diff) {
+            self.pulses.push(Pulse::One);
+        } else if PULSE_ZERO.contains(&diff) {
+            self.pulses.push(Pulse::Zero);
+        } else if PULSE_START.contains(&diff) {
+            self.pulses.push(Pulse::Start);
+        } else if diff > PULSE_END {
+            self.flush_section()?;
+        } else {
+            bail!("Invalid pulse length: {}", diff);
+        }
+
+        Ok(())
+    }
+
+    /// Turns the accumulated pulses into bits, per [`decode`]'s second
+    /// pass, and sends the section if a start sequence was found.
+    fn flush_section(&mut self) -> Result<()> {
+        for pulse in self.pulses.drain(..) {
+            match pulse {
+                Pulse::Zero => self.dat.push(false),
+                Pulse::One => self.dat.push(true),
+                Pulse::Start if self.active => {
+                    ensure!(self.dat.len().is_multiple_of(8), "Invalid start pulse")
+                }
+                Pulse::Start => self.dat.push(false),
+            }
+
+            if !self.active
+                && self.dat.len() >= 8
+                && self.dat[self.dat.len() - 8..] == START_SEQUENCE.view_bits::<Msb0>()
+            {
+                self.active = true;
+                self.dat.clear();
+            }
+        }
+
+        if self.active {
+            let _ = self.sections.send(std::mem::take(&mut self.dat));
+        } else {
+            // No start sequence was found in this section; drop the
+            // partial scan buffer instead of carrying it into the next
+            // one. (`decode` fails with an error on such a section.)
+            self.dat.clear();
+        }
+        self.active = false;
+
+        Ok(())
+    }
+}
+
+/// Refines the zero-crossing between two consecutive (same-channel)
+/// samples to a fractional sample position, per `opts.crossing_mode`.
+fn refine_crossing(
+    samples: &[i32],
+    channels: usize,
+    (prev_val, prev_idx): (i32, usize),
+    (cur_val, cur_idx): (i32, usize),
+    opts: DecodeOptions,
+) -> f64 {
+    match opts.crossing_mode {
+        CrossingMode::Linear => {
+            let (prev, cur) = (prev_val as f64, cur_val as f64);
+            prev_idx as f64
+                + (opts.crossing_limit - prev) / (cur - prev) * (cur_idx - prev_idx) as f64
+        }
+        CrossingMode::Sinc => {
+            sinc_crossing(samples, channels, prev_idx, cur_idx, opts.crossing_limit)
+        }
+    }
+}
+
+/// Binary searches the interval `[prev_idx, cur_idx]` for the position
+/// where a windowed-sinc reconstruction of `samples` crosses `limit`,
+/// narrowing until the interval is below [`SINC_SEARCH_EPSILON`] samples.
+fn sinc_crossing(
+    samples: &[i32],
+    channels: usize,
+    prev_idx: usize,
+    cur_idx: usize,
+    limit: f64,
+) -> f64 {
+    let level_at = |pos: f64| lanczos_interpolate(samples, channels, pos) - limit;
+
+    let (mut lo, mut hi) = (prev_idx as f64, cur_idx as f64);
+    let lo_sign = level_at(lo).signum();
+    while hi - lo > SINC_SEARCH_EPSILON {
+        let mid = (lo + hi) / 2.0;
+        if level_at(mid).signum() == lo_sign {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+
+    (lo + hi) / 2.0
+}
+
+/// Reconstructs the value of the single-channel signal at fractional
+/// sample position `pos` (in the flat `samples` index space) via
+/// windowed-sinc (Lanczos) interpolation.
+fn lanczos_interpolate(samples: &[i32], channels: usize, pos: f64) -> f64 {
+    let channels = channels as f64;
+    let base = (pos / channels).floor() as isize;
+    let frac = pos / channels - base as f64;
+
+    let radius = LANCZOS_A as isize;
+    let mut acc = 0.0;
+    for n in (-radius + 1)..=radius {
+        let sample_n = base + n;
+        if sample_n < 0 {
+            continue;
+        }
+
+        let idx = sample_n as usize * channels as usize;
+        if idx >= samples.len() {
+            continue;
+        }
+
+        acc += samples[idx] as f64 * lanczos_kernel(frac - n as f64);
+    }
+
+    acc
+}
+
+/// The normalized Lanczos (windowed-sinc) kernel with window size
+/// [`LANCZOS_A`].
+fn lanczos_kernel(x: f64) -> f64 {
+    if x == 0.0 {
+        return 1.0;
+    }
+    if x.abs() >= LANCZOS_A {
+        return 0.0;
+    }
+
+    let px = std::f64::consts::PI * x;
+    LANCZOS_A * px.sin() * (px / LANCZOS_A).sin() / (px * px)
+}
+
+impl Spec {
+    /// Constructs a `Spec` directly from its sample rate and channel
+    /// count, for input sources with no natural `From` conversion (e.g.
+    /// the `symphonia`-backed decoders in [`crate::source`]).
+    pub fn new(sample_rate: u32, channels: u16) -> Self {
+        Self {
+            sample_rate,
+            channels,
+        }
+    }
+}
+
 impl From<hound::WavSpec> for Spec {
     fn from(spec: hound::WavSpec) -> Self {
         Self {
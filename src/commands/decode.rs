@@ -0,0 +1,112 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use clap::{Args as ClapArgs, ValueEnum};
+
+use crate::cassette::{self, CrossingMode, DecodeOptions};
+use crate::format;
+use crate::source;
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum Interpolation {
+    Linear,
+    Sinc,
+}
+
+impl From<Interpolation> for CrossingMode {
+    fn from(interpolation: Interpolation) -> Self {
+        match interpolation {
+            Interpolation::Linear => CrossingMode::Linear,
+            Interpolation::Sinc => CrossingMode::Sinc,
+        }
+    }
+}
+
+#[derive(Debug, ClapArgs)]
+pub struct DecodeArgs {
+    /// Path to the audio file to decode (WAV, MP3, FLAC, OGG, ...).
+    pub input: PathBuf,
+
+    /// Zero-crossing interpolation strategy.
+    #[arg(long, value_enum, default_value_t = Interpolation::Linear)]
+    pub interpolation: Interpolation,
+
+    /// Track the tape's amplitude and threshold crossings against it,
+    /// instead of a fixed level.
+    #[arg(long)]
+    pub agc: bool,
+
+    /// Fraction of the local AGC envelope a sample must exceed to
+    /// register as a crossing candidate. Only applies with `--agc`.
+    #[arg(long, default_value_t = cassette::CROSS_THRESHOLD)]
+    pub agc_fraction: f32,
+
+    /// Floor for the AGC envelope, in raw PCM units, so long silent gaps
+    /// don't amplify noise into false crossings. Only applies with `--agc`.
+    #[arg(long, default_value_t = i16::MAX as f32 * cassette::CROSS_THRESHOLD)]
+    pub agc_min_level: f32,
+
+    /// Parse each section as a SYSTEM-tape program instead of printing
+    /// its raw byte count.
+    #[arg(long)]
+    pub parse: bool,
+
+    /// Write each block's payload to `<dir>/<load_addr>.bin`, named by its
+    /// load address, instead of only reporting block count and entry
+    /// address. Only applies with `--parse`.
+    #[arg(long)]
+    pub extract_dir: Option<PathBuf>,
+}
+
+pub fn decode(args: DecodeArgs) {
+    if let Err(err) = run(args) {
+        eprintln!("Error: {err:?}");
+        std::process::exit(1);
+    }
+}
+
+fn run(args: DecodeArgs) -> Result<()> {
+    let (samples, spec) = source::read(&args.input)?;
+
+    let opts = DecodeOptions {
+        crossing_mode: args.interpolation.into(),
+        agc: cassette::AgcOptions {
+            enabled: args.agc,
+            threshold_fraction: args.agc_fraction,
+            min_level: args.agc_min_level,
+        },
+        ..Default::default()
+    };
+
+    let sections = cassette::decode_with_options(&samples, spec, opts)?;
+    for (i, section) in sections.iter().enumerate() {
+        if !args.parse {
+            println!("Section {i}: {} bytes", section.len() / 8);
+            continue;
+        }
+
+        let (cas, mismatches) = format::parse(section)?;
+        println!(
+            "Section {i}: {:?} ({} blocks, entry {:#06x})",
+            cas.name,
+            cas.blocks.len(),
+            cas.entry
+        );
+        for mismatch in mismatches {
+            println!(
+                "  block {}: checksum mismatch (expected {:#04x}, got {:#04x})",
+                mismatch.block_index, mismatch.expected, mismatch.actual
+            );
+        }
+
+        if let Some(dir) = &args.extract_dir {
+            std::fs::create_dir_all(dir)?;
+            for block in &cas.blocks {
+                let path = dir.join(format!("{:04x}.bin", block.load_addr));
+                std::fs::write(&path, &block.data)?;
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use clap::{Args as ClapArgs, ValueEnum};
+use hound::{SampleFormat, WavSpec, WavWriter};
+
+use crate::encode::{self, EncodeOptions, Waveform};
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum WaveformArg {
+    Square,
+    Sine,
+}
+
+impl From<WaveformArg> for Waveform {
+    fn from(waveform: WaveformArg) -> Self {
+        match waveform {
+            WaveformArg::Square => Waveform::Square,
+            WaveformArg::Sine => Waveform::Sine,
+        }
+    }
+}
+
+#[derive(Debug, ClapArgs)]
+pub struct EncodeArgs {
+    /// Path to the file whose bytes should be encoded.
+    pub input: PathBuf,
+
+    /// Path to write the synthesized WAV to.
+    pub output: PathBuf,
+
+    /// Pulse waveform shape.
+    #[arg(long, value_enum, default_value_t = WaveformArg::Square)]
+    pub waveform: WaveformArg,
+
+    /// Carrier tone length, in seconds, before the sync byte.
+    #[arg(long, default_value_t = 1.0)]
+    pub leader: f32,
+
+    /// Silence length, in seconds, appended after the data.
+    #[arg(long, default_value_t = 1.0)]
+    pub trailer: f32,
+
+    /// Output sample rate.
+    #[arg(long, default_value_t = 44100)]
+    pub sample_rate: u32,
+}
+
+pub fn encode(args: EncodeArgs) {
+    if let Err(err) = run(args) {
+        eprintln!("Error: {err:?}");
+        std::process::exit(1);
+    }
+}
+
+fn run(args: EncodeArgs) -> Result<()> {
+    let data = std::fs::read(&args.input)?;
+
+    let opts = EncodeOptions {
+        waveform: args.waveform.into(),
+        leader: args.leader,
+        trailer: args.trailer,
+    };
+    let samples = encode::encode(&data, args.sample_rate, opts);
+
+    let spec = WavSpec {
+        channels: 1,
+        sample_rate: args.sample_rate,
+        bits_per_sample: 16,
+        sample_format: SampleFormat::Int,
+    };
+    let mut writer = WavWriter::create(&args.output, spec)?;
+    for sample in samples {
+        writer.write_sample(sample as i16)?;
+    }
+    writer.finalize()?;
+
+    Ok(())
+}
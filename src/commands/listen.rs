@@ -0,0 +1,109 @@
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+
+use anyhow::{bail, Context, Result};
+use clap::Args as ClapArgs;
+use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+use cpal::{SampleFormat, Stream, StreamConfig};
+
+use crate::cassette::{DecodeOptions, Decoder};
+
+#[derive(Debug, ClapArgs)]
+pub struct ListenArgs {
+    /// Input channel to sample, matching the channel layout reported by
+    /// the device (e.g. `0` for left on a stereo interface).
+    #[arg(long, default_value_t = 0)]
+    pub channel: u16,
+}
+
+pub fn listen(args: ListenArgs) {
+    if let Err(err) = run(args) {
+        eprintln!("Error: {err:?}");
+        std::process::exit(1);
+    }
+}
+
+fn run(args: ListenArgs) -> Result<()> {
+    let host = cpal::default_host();
+    let device = host
+        .default_input_device()
+        .context("no input device available")?;
+    let config = device.default_input_config()?;
+    let sample_format = config.sample_format();
+    let stream_config = config.config();
+
+    let (tx, rx) = mpsc::channel();
+    // Shared with the stream callback so `run` can still reach the decoder
+    // once the stream is torn down, to flush whatever section was still in
+    // progress via `Decoder::finish`.
+    let decoder = Arc::new(Mutex::new(Some(Decoder::new(
+        config.into(),
+        args.channel,
+        DecodeOptions::default(),
+        tx,
+    ))));
+
+    // cpal requires the stream to be built with the device's native
+    // sample type; widen each format to `i32` the same way `misc`/`source`
+    // already do before handing samples to the decoder.
+    let stream = match sample_format {
+        SampleFormat::F32 => build_stream(&device, &stream_config, decoder.clone(), |s: f32| {
+            (s * i16::MAX as f32) as i32
+        })?,
+        SampleFormat::I16 => {
+            build_stream(&device, &stream_config, decoder.clone(), |s: i16| s as i32)?
+        }
+        SampleFormat::U16 => build_stream(&device, &stream_config, decoder.clone(), |s: u16| {
+            s as i32 - 32768
+        })?,
+        other => bail!("unsupported input sample format: {other:?}"),
+    };
+    stream.play()?;
+
+    let printer = thread::spawn(move || {
+        for section in rx {
+            println!("Section: {} bytes", section.len() / 8);
+        }
+    });
+
+    println!("Listening... press enter to stop.");
+    std::io::stdin().read_line(&mut String::new())?;
+
+    // Dropping the stream stops further callbacks; finishing the decoder
+    // flushes whatever pulse train was still mid-section so it isn't
+    // silently lost.
+    drop(stream);
+    if let Some(decoder) = decoder.lock().unwrap().take() {
+        decoder.finish()?;
+    }
+    let _ = printer.join();
+
+    Ok(())
+}
+
+/// Builds an input stream of native sample type `S`, converting each
+/// sample to `i32` with `to_i32` before feeding it to `decoder`.
+fn build_stream<S>(
+    device: &cpal::Device,
+    config: &StreamConfig,
+    decoder: Arc<Mutex<Option<Decoder>>>,
+    to_i32: impl Fn(S) -> i32 + Send + 'static,
+) -> Result<Stream>
+where
+    S: cpal::Sample + cpal::SizedSample,
+{
+    let stream = device.build_input_stream(
+        config,
+        move |data: &[S], _: &cpal::InputCallbackInfo| {
+            let samples: Vec<i32> = data.iter().map(|&s| to_i32(s)).collect();
+            if let Some(decoder) = decoder.lock().unwrap().as_mut() {
+                if let Err(err) = decoder.push(&samples) {
+                    eprintln!("Error: {err:?}");
+                }
+            }
+        },
+        |err| eprintln!("Stream error: {err}"),
+        None,
+    )?;
+    Ok(stream)
+}
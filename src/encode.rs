@@ -0,0 +1,144 @@
+use std::ops::Range;
+
+use bitvec::{order::Msb0, view::BitView};
+
+use crate::cassette::{PULSE_ONE, PULSE_START, PULSE_ZERO, START_SEQUENCE};
+
+/// Peak amplitude of synthesized pulses, in raw PCM units.
+const AMPLITUDE: f32 = i16::MAX as f32 * 0.8;
+
+/// Waveform shape used to synthesize each pulse.
+#[derive(Debug, Clone, Copy, Default)]
+pub enum Waveform {
+    #[default]
+    Square,
+    Sine,
+}
+
+/// Options controlling the synthesized cassette audio.
+#[derive(Debug, Clone, Copy)]
+pub struct EncodeOptions {
+    /// Pulse waveform shape.
+    pub waveform: Waveform,
+    /// Length, in seconds, of the carrier tone emitted before the sync byte.
+    pub leader: f32,
+    /// Length, in seconds, of silence appended after the data.
+    pub trailer: f32,
+}
+
+impl Default for EncodeOptions {
+    fn default() -> Self {
+        Self {
+            waveform: Waveform::default(),
+            leader: 1.0,
+            trailer: 1.0,
+        }
+    }
+}
+
+/// Synthesizes mono PCM samples encoding `data` as a TRS-80 cassette
+/// signal at `sample_rate` — the exact inverse of [`crate::cassette::decode`].
+///
+/// Each bit becomes one cycle of `opts.waveform` whose period is the
+/// midpoint of the matching `PULSE_*` range, so [`crate::cassette::decode`]
+/// classifies it back to the same bit. The cycle starts and ends on a
+/// negative-to-positive crossing, so consecutive pulses chain into the
+/// crossings `decode` looks for without resetting phase between them.
+pub fn encode(data: &[u8], sample_rate: u32, opts: EncodeOptions) -> Vec<i32> {
+    let mut samples = Vec::new();
+
+    let one_period = pulse_period(PULSE_ONE);
+    let mut leader_elapsed = 0.0;
+    while leader_elapsed < opts.leader {
+        push_cycle(&mut samples, sample_rate, one_period, opts.waveform);
+        leader_elapsed += one_period;
+    }
+
+    // The leading zero bit of the 0x7F sync byte, followed by its seven
+    // one bits, mirrors how `decode` treats a `Pulse::Start` before it has
+    // found the start sequence.
+    push_cycle(
+        &mut samples,
+        sample_rate,
+        pulse_period(PULSE_START),
+        opts.waveform,
+    );
+    for bit in START_SEQUENCE.view_bits::<Msb0>().iter().skip(1) {
+        push_bit(&mut samples, sample_rate, *bit, opts.waveform);
+    }
+
+    for &byte in data {
+        for bit in byte.view_bits::<Msb0>().iter() {
+            push_bit(&mut samples, sample_rate, *bit, opts.waveform);
+        }
+        push_cycle(
+            &mut samples,
+            sample_rate,
+            pulse_period(PULSE_START),
+            opts.waveform,
+        );
+    }
+
+    let trailer_samples = (opts.trailer * sample_rate as f32) as usize;
+    samples.extend(std::iter::repeat_n(0, trailer_samples));
+
+    samples
+}
+
+fn push_bit(samples: &mut Vec<i32>, sample_rate: u32, bit: bool, waveform: Waveform) {
+    let period = pulse_period(if bit { PULSE_ONE } else { PULSE_ZERO });
+    push_cycle(samples, sample_rate, period, waveform);
+}
+
+fn push_cycle(samples: &mut Vec<i32>, sample_rate: u32, period: f32, waveform: Waveform) {
+    let n = (period * sample_rate as f32).round().max(1.0) as usize;
+    for i in 0..n {
+        let t = i as f32 / n as f32;
+        let value = match waveform {
+            Waveform::Square => {
+                if t < 0.5 {
+                    AMPLITUDE
+                } else {
+                    -AMPLITUDE
+                }
+            }
+            Waveform::Sine => AMPLITUDE * (2.0 * std::f32::consts::PI * t).sin(),
+        };
+        samples.push(value as i32);
+    }
+}
+
+fn pulse_period(range: Range<f32>) -> f32 {
+    (range.start + range.end) / 2.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cassette::{decode, Spec};
+
+    #[test]
+    fn round_trips_every_byte() {
+        const SAMPLE_RATE: u32 = 44100;
+
+        for byte in 0u8..=255 {
+            let data = [byte];
+            let samples = encode(&data, SAMPLE_RATE, EncodeOptions::default());
+
+            let sections = decode(&samples, Spec::new(SAMPLE_RATE, 1))
+                .unwrap_or_else(|err| panic!("failed to decode byte {byte:#04x}: {err}"));
+
+            assert_eq!(
+                sections.len(),
+                1,
+                "byte {byte:#04x} produced {} sections",
+                sections.len()
+            );
+            assert_eq!(
+                sections[0].clone().into_vec(),
+                data.to_vec(),
+                "byte {byte:#04x} round-trip mismatch"
+            );
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use anyhow::{bail, ensure, Result};
+use bitvec::{order::Msb0, vec::BitVec};
+
+/// Sync byte preceding the SYSTEM-tape header and each subsequent block.
+const SYNC_BYTE: u8 = 0xA5;
+/// Marks the SYSTEM-tape file header, followed by a six-character name.
+const HEADER_MARKER: u8 = 0x55;
+/// Marks a data block: length byte, two-byte load address, payload, checksum.
+const DATA_BLOCK_MARKER: u8 = 0x3C;
+/// Marks the entry-point record, followed by a two-byte transfer address.
+const ENTRY_POINT_MARKER: u8 = 0x78;
+
+/// A single relocatable chunk of a SYSTEM-tape program.
+#[derive(Debug)]
+pub struct Block {
+    pub load_addr: u16,
+    pub data: Vec<u8>,
+}
+
+/// A fully parsed TRS-80 SYSTEM-tape program.
+#[derive(Debug)]
+pub struct CasFile {
+    pub name: String,
+    pub blocks: Vec<Block>,
+    pub entry: u16,
+}
+
+/// A block whose stored checksum didn't match its address + payload.
+#[derive(Debug)]
+pub struct ChecksumMismatch {
+    pub block_index: usize,
+    pub expected: u8,
+    pub actual: u8,
+}
+
+/// Walks a decoded byte stream into a [`CasFile`], verifying each data
+/// block's checksum rather than silently accepting corrupt data.
+///
+/// Mismatches are collected and returned alongside the file rather than
+/// failing the parse outright, so a caller can decide whether to trust a
+/// block with a bad checksum.
+pub fn parse(section: &BitVec<u8, Msb0>) -> Result<(CasFile, Vec<ChecksumMismatch>)> {
+    ensure!(
+        section.len().is_multiple_of(8),
+        "Section is not byte-aligned"
+    );
+    let bytes = section.clone().into_vec();
+
+    let mut pos = skip_sync(&bytes, 0);
+    ensure!(
+        bytes.get(pos) == Some(&HEADER_MARKER),
+        "Missing SYSTEM-tape header"
+    );
+    pos += 1;
+
+    ensure!(pos + 6 <= bytes.len(), "Truncated header name");
+    let name = String::from_utf8_lossy(&bytes[pos..pos + 6]).into_owned();
+    pos += 6;
+
+    let mut blocks = Vec::new();
+    let mut mismatches = Vec::new();
+    loop {
+        pos = skip_sync(&bytes, pos);
+        match bytes.get(pos) {
+            Some(&DATA_BLOCK_MARKER) => {
+                pos += 1;
+                ensure!(pos + 3 <= bytes.len(), "Truncated data block");
+                // A length byte of 0x00 means a full 256-byte block, not
+                // an empty one.
+                let len = match bytes[pos] {
+                    0 => 256,
+                    n => n as usize,
+                };
+                let load_addr = u16::from_le_bytes([bytes[pos + 1], bytes[pos + 2]]);
+                pos += 3;
+
+                ensure!(pos + len < bytes.len(), "Truncated data block payload");
+                let data = bytes[pos..pos + len].to_vec();
+                pos += len;
+                let checksum = bytes[pos];
+                pos += 1;
+
+                let computed = block_checksum(load_addr, &data);
+                if computed != checksum {
+                    mismatches.push(ChecksumMismatch {
+                        block_index: blocks.len(),
+                        expected: checksum,
+                        actual: computed,
+                    });
+                }
+                blocks.push(Block { load_addr, data });
+            }
+            Some(&ENTRY_POINT_MARKER) => {
+                pos += 1;
+                ensure!(pos + 2 <= bytes.len(), "Truncated entry-point record");
+                let entry = u16::from_le_bytes([bytes[pos], bytes[pos + 1]]);
+                return Ok((
+                    CasFile {
+                        name,
+                        blocks,
+                        entry,
+                    },
+                    mismatches,
+                ));
+            }
+            Some(other) => bail!("Unexpected block marker: {other:#04x}"),
+            None => bail!("Missing entry-point record"),
+        }
+    }
+}
+
+/// The 8-bit sum of a block's load address and payload.
+fn block_checksum(load_addr: u16, data: &[u8]) -> u8 {
+    let [lo, hi] = load_addr.to_le_bytes();
+    data.iter()
+        .fold(lo.wrapping_add(hi), |sum, &b| sum.wrapping_add(b))
+}
+
+/// Advances past any run of [`SYNC_BYTE`] starting at `pos`.
+fn skip_sync(bytes: &[u8], mut pos: usize) -> usize {
+    while bytes.get(pos) == Some(&SYNC_BYTE) {
+        pos += 1;
+    }
+    pos
+}
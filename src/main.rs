@@ -4,12 +4,17 @@ use clap::Parser;
 mod args;
 mod cassette;
 mod commands;
+mod encode;
+mod format;
 mod misc;
+mod source;
 
 fn main() {
     let args = args::Args::parse();
 
     match args.subcommand {
         Command::Decode(decode) => commands::decode::decode(decode),
+        Command::Listen(listen) => commands::listen::listen(listen),
+        Command::Encode(encode) => commands::encode::encode(encode),
     }
 }
@@ -0,0 +1,15 @@
+use std::path::Path;
+
+use anyhow::Result;
+use hound::{WavReader, WavSpec};
+
+/// Reads every sample of a WAV file as widened `i32` PCM, regardless of
+/// the file's native bit depth.
+pub fn read_wav(path: impl AsRef<Path>) -> Result<(Vec<i32>, WavSpec)> {
+    let reader = WavReader::open(path)?;
+    let spec = reader.spec();
+    let samples = reader
+        .into_samples::<i32>()
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok((samples, spec))
+}
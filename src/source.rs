@@ -0,0 +1,119 @@
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use symphonia::core::{
+    audio::{AudioBuffer, AudioBufferRef, Signal},
+    codecs::DecoderOptions,
+    formats::FormatOptions,
+    io::MediaSourceStream,
+    meta::MetadataOptions,
+    probe::Hint,
+    sample::Sample,
+};
+
+use crate::cassette::Spec;
+use crate::misc;
+
+/// Reads an audio file of any supported container (WAV, or anything
+/// `symphonia` can demux and decode, e.g. MP3/FLAC/OGG) into widened
+/// `i32` PCM samples and the stream's [`Spec`], so
+/// [`crate::cassette::decode`] can consume it uniformly regardless of the
+/// source format. The container is auto-detected from the file's
+/// extension and magic bytes.
+///
+/// Samples stay interleaved across all channels rather than being
+/// downmixed, so callers select a channel the same way `decode` already
+/// does: by taking every `channels`-th sample.
+pub fn read(path: impl AsRef<Path>) -> Result<(Vec<i32>, Spec)> {
+    let path = path.as_ref();
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("wav") => {
+            let (samples, spec) = misc::read_wav(path)?;
+            Ok((samples, spec.into()))
+        }
+        _ => read_with_symphonia(path),
+    }
+}
+
+fn read_with_symphonia(path: &Path) -> Result<(Vec<i32>, Spec)> {
+    let file = std::fs::File::open(path)?;
+    let mss = MediaSourceStream::new(Box::new(file), Default::default());
+
+    let mut hint = Hint::new();
+    if let Some(ext) = path.extension().and_then(|ext| ext.to_str()) {
+        hint.with_extension(ext);
+    }
+
+    let probed = symphonia::default::get_probe().format(
+        &hint,
+        mss,
+        &FormatOptions::default(),
+        &MetadataOptions::default(),
+    )?;
+    let mut format = probed.format;
+
+    let track = format.default_track().context("no default track")?;
+    let track_id = track.id;
+    let channels = track
+        .codec_params
+        .channels
+        .context("unknown channel layout")?
+        .count() as u16;
+    let sample_rate = track
+        .codec_params
+        .sample_rate
+        .context("unknown sample rate")?;
+
+    let mut decoder =
+        symphonia::default::get_codecs().make(&track.codec_params, &DecoderOptions::default())?;
+
+    let mut samples = Vec::new();
+    loop {
+        let packet = match format.next_packet() {
+            Ok(packet) => packet,
+            Err(symphonia::core::errors::Error::IoError(err))
+                if err.kind() == std::io::ErrorKind::UnexpectedEof =>
+            {
+                break;
+            }
+            Err(err) => return Err(err.into()),
+        };
+        if packet.track_id() != track_id {
+            continue;
+        }
+
+        let decoded = decoder.decode(&packet)?;
+        push_interleaved(&decoded, &mut samples);
+    }
+
+    Ok((samples, Spec::new(sample_rate, channels)))
+}
+
+/// Appends every channel of a decoded audio buffer to `out`, interleaved
+/// frame-by-frame, widening each sample to `i32` at its *native* bit
+/// depth rather than rescaling to the full 32-bit range — matching the
+/// convention `misc::read_wav` uses via `hound::into_samples::<i32>()`,
+/// which `decode`'s fixed threshold and AGC floor are calibrated against.
+fn push_interleaved(buf: &AudioBufferRef, out: &mut Vec<i32>) {
+    match buf {
+        AudioBufferRef::U8(buf) => push_planes(buf, out, |s| (s as i32) - 128),
+        AudioBufferRef::U16(buf) => push_planes(buf, out, |s| (s as i32) - 32768),
+        AudioBufferRef::U24(buf) => push_planes(buf, out, |s| s.inner() as i32 - (1 << 23)),
+        AudioBufferRef::U32(buf) => push_planes(buf, out, |s| (s as i64 - (1i64 << 31)) as i32),
+        AudioBufferRef::S8(buf) => push_planes(buf, out, |s| s as i32),
+        AudioBufferRef::S16(buf) => push_planes(buf, out, |s| s as i32),
+        AudioBufferRef::S24(buf) => push_planes(buf, out, |s| s.inner()),
+        AudioBufferRef::S32(buf) => push_planes(buf, out, |s| s),
+        AudioBufferRef::F32(buf) => push_planes(buf, out, |s| (s * i16::MAX as f32) as i32),
+        AudioBufferRef::F64(buf) => push_planes(buf, out, |s| (s * i16::MAX as f64) as i32),
+    }
+}
+
+fn push_planes<S: Sample>(buf: &AudioBuffer<S>, out: &mut Vec<i32>, to_i32: impl Fn(S) -> i32) {
+    let channels = buf.spec().channels.count();
+    for frame in 0..buf.frames() {
+        for channel in 0..channels {
+            out.push(to_i32(buf.chan(channel)[frame]));
+        }
+    }
+}